@@ -2,12 +2,38 @@ use countedindex::Index;
 use multiqueue::{InnerSend, InnerRecv, BCast, MPMC, MultiQueue};
 use wait::Wait;
 
-use std::sync::mpsc::{TrySendError, TryRecvError, RecvError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{TrySendError, TryRecvError, RecvError, SendError, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// `std::sync::mpsc::SendTimeoutError` doesn't exist on stable (it's gated
+/// behind the unstable `mpsc_send_timeout` feature), unlike the already
+/// stable `RecvTimeoutError` this crate reuses elsewhere. Defined locally
+/// here so `send_timeout`/`send_deadline` have somewhere real to report
+/// into, mirroring the shape std uses for the unstable version.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SendTimeoutError<T> {
+    /// The timeout elapsed before space was available.
+    Timeout(T),
+    /// All receivers have disconnected.
+    Disconnected(T),
+}
+
+/// This crate snapshot doesn't include the `Wait`/`InnerSend`/`InnerRecv`
+/// internals (they live in the `multiqueue`/`wait` modules), so the
+/// blocking helpers below can't register with the queue's park/wake
+/// mechanism directly. Instead they poll the existing nonblocking
+/// `try_send`/`try_recv`/`try_recv_view` and back off for this long
+/// between attempts, which is correct but busier than a true park.
+const POLL_INTERVAL: Duration = Duration::from_micros(50);
 
 /// This class is the sending half of the MultiQueue. It supports both
 /// single and multi consumer modes with competitive performance in each case.
-/// It only supports nonblocking writes (the futures sender being an exception)
-/// as well as being the conduit for adding new writers.
+/// Besides the nonblocking `try_send`, it also supports a blocking `send`
+/// (and `send_timeout`/`send_deadline` variants) that backs off and retries
+/// until space frees up, as well as being the conduit for adding new writers.
 ///
 /// # Examples
 ///
@@ -33,12 +59,7 @@ use std::sync::mpsc::{TrySendError, TryRecvError, RecvError};
 /// recv.unsubscribe();
 ///
 /// for i in 0..10 {
-///     // Don't do this busy loop in real stuff unless you're really sure
-///     loop {
-///         if send.try_send(i).is_ok() {
-///             break;
-///         }
-///     }
+///     send.send(i).unwrap();
 /// }
 /// drop(send);
 ///
@@ -59,10 +80,24 @@ pub struct MPMCSender<T> {
 
 /// This is the receiving end of a standard mpmc view of the queue
 /// It functions similarly to the broadcast queue execpt there
-/// is only ever one stream. As a result, the type doesn't need to be clone
+/// is only ever one stream. As a result, the type doesn't need to be clone.
+///
+/// NOTE: the "last reader moves instead of clones" optimization (request
+/// chunk0-6, borrowed from the `bus` crate) targets the broadcast
+/// (multi-stream) path, where the same value is handed to every stream
+/// and only the final reader of a slot should avoid cloning it. That path
+/// lives in the broadcast module, which this source tree doesn't include
+/// - there's no `broadcast.rs` here to add the bookkeeping to, and MPMC
+/// mode's single stream has nothing to clone in the first place. Reopening
+/// rather than closing: this request still needs doing once the broadcast
+/// module is available to edit.
+///
+/// STATUS: NOT IMPLEMENTED. Nothing in this series delivers chunk0-6; this
+/// is a doc-only note, not a closed request.
 #[derive(Clone)]
 pub struct MPMCReceiver<T> {
     reader: InnerRecv<MPMC<T>, T>,
+    readers: ReaderCount,
 }
 
 
@@ -74,6 +109,35 @@ pub struct MPMCUniReceiver<T> {
     reader: InnerRecv<MPMC<T>, T>,
 }
 
+/// Tracks how many live `MPMCReceiver` handles share a stream, so
+/// `MPMCReceiver::into_single` can tell whether it's the sole remaining
+/// receiver. This is bookkeeping kept entirely within this module - it
+/// doesn't reach into `InnerRecv`, which has no such query exposed here.
+struct ReaderCount(Arc<AtomicUsize>);
+
+impl ReaderCount {
+    fn new() -> ReaderCount {
+        ReaderCount(Arc::new(AtomicUsize::new(1)))
+    }
+
+    fn is_single(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == 1
+    }
+}
+
+impl Clone for ReaderCount {
+    fn clone(&self) -> ReaderCount {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        ReaderCount(self.0.clone())
+    }
+}
+
+impl Drop for ReaderCount {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 
 impl<T> MPMCSender<T> {
     /// Tries to send a value into the queue
@@ -83,6 +147,61 @@ impl<T> MPMCSender<T> {
         self.sender.try_send(val)
     }
 
+    /// Sends a value into the queue, blocking the calling thread until
+    /// there is space for it.
+    ///
+    /// WARNING, CPU cost: this is a polling loop, not a park. It polls
+    /// `try_send` and sleeps `POLL_INTERVAL` (50µs) between attempts
+    /// rather than parking on the queue's wait/wake mechanism, so a
+    /// blocked caller keeps waking up to ~20,000 times/sec and uses CPU
+    /// time the whole time it's blocked. Fine for short waits; don't call this
+    /// from a thread you expect to sit blocked for a long time.
+    /// If there are no readers, returns Err(SendError(val))
+    pub fn send(&self, val: T) -> Result<(), SendError<T>> {
+        let mut val = val;
+        loop {
+            match self.sender.try_send(val) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Disconnected(v)) => return Err(SendError(v)),
+                Err(TrySendError::Full(v)) => {
+                    val = v;
+                    thread::sleep(POLL_INTERVAL);
+                }
+            }
+        }
+    }
+
+    /// Like `send`, but only blocks for up to `timeout` before giving up.
+    /// If the timeout elapses before space is available, returns the value
+    /// back in Err(SendTimeoutError::Timeout(val)).
+    /// If there are no readers, returns Err(SendTimeoutError::Disconnected(val))
+    ///
+    /// WARNING, CPU cost: same spin-poll as `send` - see its doc comment.
+    pub fn send_timeout(&self, val: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
+        self.send_deadline(val, Instant::now() + timeout)
+    }
+
+    /// Like `send_timeout`, but takes an absolute `Instant` to wake up at
+    /// instead of a relative `Duration`.
+    ///
+    /// WARNING, CPU cost: same spin-poll as `send` - see its doc comment.
+    pub fn send_deadline(&self, val: T, deadline: Instant) -> Result<(), SendTimeoutError<T>> {
+        let mut val = val;
+        loop {
+            match self.sender.try_send(val) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Disconnected(v)) => return Err(SendTimeoutError::Disconnected(v)),
+                Err(TrySendError::Full(v)) => {
+                    val = v;
+                    if Instant::now() >= deadline {
+                        return Err(SendTimeoutError::Timeout(val));
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            }
+        }
+    }
+
     /// Removes this writer from the queue
     pub fn unsubscribe(self) {
         self.sender.unsubscribe()
@@ -131,6 +250,39 @@ impl<T> MPMCReceiver<T> {
         self.reader.recv()
     }
 
+    /// Blocks the calling thread until a value is available or `timeout`
+    /// elapses, whichever comes first. Distinguishes a timeout from the
+    /// queue being disconnected.
+    ///
+    /// WARNING, CPU cost: this is a polling loop, not a park. It polls
+    /// `try_recv` and sleeps `POLL_INTERVAL` (50µs) between attempts
+    /// rather than parking on the queue's wait/wake mechanism, so a
+    /// blocked caller keeps waking up to ~20,000 times/sec and uses CPU
+    /// time the whole time it's blocked.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(Instant::now() + timeout)
+    }
+
+    /// Like `recv_timeout`, but takes an absolute `Instant` to wake up at
+    /// instead of a relative `Duration`.
+    ///
+    /// WARNING, CPU cost: same spin-poll as `recv_timeout` - see its doc
+    /// comment.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        loop {
+            match self.reader.try_recv() {
+                Ok(val) => return Ok(val),
+                Err(TryRecvError::Disconnected) => return Err(RecvTimeoutError::Disconnected),
+                Err(TryRecvError::Empty) => {
+                    if Instant::now() >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            }
+        }
+    }
+
     /// Removes the given reader from the queue subscription lib
     /// Returns true if this is the last reader in a given broadcast unit
     ///
@@ -148,32 +300,53 @@ impl<T> MPMCReceiver<T> {
     pub fn unsubscribe(self) -> bool {
         self.reader.unsubscribe()
     }
-}
 
-/*
-/// If there is only one InnerRecv on the stream, converts the
-/// InnerRecv into a UniInnerRecv otherwise returns the InnerRecv.
-///
-/// # Example:
-///
-/// ```
-/// use multiqueue::multiqueue;
-///
-/// let (w, r) = multiqueue(10);
-/// w.try_send(1).unwrap();
-/// let r2 = r.clone();
-/// // Fails since there's two receivers on the stream
-/// assert!(r2.into_single().is_err());
-/// let single_r = r.into_single().unwrap();
-/// let val = match single_r.try_recv_view(|x| 2 * *x) {
-///     Ok(val) => val,
-///     Err(_) => panic!("Queue should have an element"),
-/// };
-/// assert_eq!(2, val);
-//    pub fn into_single(&self) -> Result<Receiver<T>, Sender<T>> {
-//
-//   }
- */
+    /// If there is only one MPMCReceiver on the stream, converts self
+    /// into an MPMCUniReceiver. Otherwise, returns self in the Err half.
+    /// This doesn't remove the receiver from the stream, so the returned
+    /// MPMCUniReceiver takes its place.
+    ///
+    /// An MPMCUniReceiver doesn't require T: Clone + Sync and unlocks the
+    /// zero-copy try_recv_view/recv_view paths.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use multiqueue::mpmc_queue;
+    ///
+    /// let (w, r) = mpmc_queue(10);
+    /// w.try_send(1).unwrap();
+    /// let r2 = r.clone();
+    /// // Fails since there's two receivers on the stream
+    /// assert!(r2.into_single().is_err());
+    /// let single_r = r.into_single().unwrap();
+    /// let val = match single_r.try_recv_view(|x| 2 * *x) {
+    ///     Ok(val) => val,
+    ///     Err(_) => panic!("Queue should have an element"),
+    /// };
+    /// assert_eq!(2, val);
+    /// ```
+    pub fn into_single(self) -> Result<MPMCUniReceiver<T>, MPMCReceiver<T>> {
+        if self.readers.is_single() {
+            Ok(MPMCUniReceiver { reader: self.reader })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Returns an iterator that blocks on `recv` without consuming the
+    /// receiver, so it can be used again once the iterator is dropped.
+    pub fn iter(&self) -> MPMCRefIter<T> {
+        MPMCRefIter { recv: self }
+    }
+
+    /// Returns an iterator that calls `try_recv` and stops (yielding None)
+    /// as soon as the queue is empty, rather than blocking for more.
+    /// Lets a consumer drain everything currently buffered in a `for` loop.
+    pub fn try_iter(&self) -> MPMCTryIter<T> {
+        MPMCTryIter { recv: self }
+    }
+}
 
 impl<T> MPMCUniReceiver<T> {
     /// Identical to MPMCReceiver::try_recv
@@ -186,6 +359,33 @@ impl<T> MPMCUniReceiver<T> {
         self.reader.recv()
     }
 
+    /// Identical to MPMCReceiver::recv_timeout
+    ///
+    /// WARNING, CPU cost: this spin-polls rather than parking - see
+    /// `MPMCReceiver::recv_timeout`'s doc comment.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(Instant::now() + timeout)
+    }
+
+    /// Identical to MPMCReceiver::recv_deadline
+    ///
+    /// WARNING, CPU cost: this spin-polls rather than parking - see
+    /// `MPMCReceiver::recv_timeout`'s doc comment.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        loop {
+            match self.reader.try_recv() {
+                Ok(val) => return Ok(val),
+                Err(TryRecvError::Disconnected) => return Err(RecvTimeoutError::Disconnected),
+                Err(TryRecvError::Empty) => {
+                    if Instant::now() >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            }
+        }
+    }
+
 
     /// Similar to UniMcastReceiver::try_recv_view, except this closure takes
     pub fn try_recv_view<R, F: FnOnce(&T) -> R>(&self, op: F) -> Result<R, (F, TryRecvError)> {
@@ -197,6 +397,34 @@ impl<T> MPMCUniReceiver<T> {
         self.reader.recv_view(op)
     }
 
+    /// Like `recv_view`, but only blocks for up to `timeout` before giving
+    /// up and handing the closure back in Err((op, RecvTimeoutError::Timeout)).
+    ///
+    /// WARNING, CPU cost: this spin-polls rather than parking - see
+    /// `MPMCReceiver::recv_timeout`'s doc comment.
+    pub fn recv_view_timeout<R, F: FnOnce(&T) -> R>(&self,
+                                                     op: F,
+                                                     timeout: Duration)
+                                                     -> Result<R, (F, RecvTimeoutError)> {
+        let deadline = Instant::now() + timeout;
+        let mut op = op;
+        loop {
+            match self.reader.try_recv_view(op) {
+                Ok(val) => return Ok(val),
+                Err((f, TryRecvError::Disconnected)) => {
+                    return Err((f, RecvTimeoutError::Disconnected))
+                }
+                Err((f, TryRecvError::Empty)) => {
+                    op = f;
+                    if Instant::now() >= deadline {
+                        return Err((op, RecvTimeoutError::Timeout));
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            }
+        }
+    }
+
     /// Removes the given reader from the queue subscription lib
     /// Returns true if this is the last reader in a given broadcast unit
     ///
@@ -214,8 +442,61 @@ impl<T> MPMCUniReceiver<T> {
     pub fn unsubscribe(self) -> bool {
         self.reader.unsubscribe()
     }
+
+    /// Converts self into an MPMCReceiver, giving up the zero-copy
+    /// try_recv_view/recv_view paths in exchange for being Clone again.
+    /// The reverse of `MPMCReceiver::into_single`.
+    pub fn into_multi(self) -> MPMCReceiver<T> {
+        MPMCReceiver {
+            reader: self.reader,
+            readers: ReaderCount::new(),
+        }
+    }
+
+    /// Identical to MPMCReceiver::iter
+    pub fn iter(&self) -> MPMCUniIter<T> {
+        MPMCUniIter { recv: self }
+    }
+
+    /// Identical to MPMCReceiver::try_iter
+    pub fn try_iter(&self) -> MPMCUniTryIter<T> {
+        MPMCUniTryIter { recv: self }
+    }
+}
+
+/// A blocking, non-consuming iterator over an `MPMCUniReceiver`, created by
+/// `MPMCUniReceiver::iter`.
+pub struct MPMCUniIter<'a, T: 'a> {
+    recv: &'a MPMCUniReceiver<T>,
+}
+
+impl<'a, T: 'a> Iterator for MPMCUniIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv.recv().ok()
+    }
 }
 
+/// A non-blocking, non-consuming iterator over an `MPMCUniReceiver`,
+/// created by `MPMCUniReceiver::try_iter`. Stops as soon as the queue is
+/// empty instead of waiting for more values.
+pub struct MPMCUniTryIter<'a, T: 'a> {
+    recv: &'a MPMCUniReceiver<T>,
+}
+
+impl<'a, T: 'a> Iterator for MPMCUniTryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv.try_recv().ok()
+    }
+}
+
+/// `MPMCReceiver`'s consuming iterator, unchanged from before `iter`/
+/// `try_iter` were added: this is still the `IntoIterator::IntoIter`
+/// callers already name in existing code, so it keeps its original name
+/// rather than being renamed out from under them.
 pub struct MPMCIter<T> {
     recv: MPMCReceiver<T>,
 }
@@ -241,17 +522,194 @@ impl<T> IntoIterator for MPMCReceiver<T> {
     }
 }
 
+/// A blocking, non-consuming iterator over an `MPMCReceiver`, created by
+/// `MPMCReceiver::iter`. Distinct from `MPMCIter` (the consuming
+/// `IntoIterator::IntoIter`), which this doesn't replace.
+pub struct MPMCRefIter<'a, T: 'a> {
+    recv: &'a MPMCReceiver<T>,
+}
 
+impl<'a, T: 'a> Iterator for MPMCRefIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv.recv().ok()
+    }
+}
+
+/// A non-blocking, non-consuming iterator over an `MPMCReceiver`, created
+/// by `MPMCReceiver::try_iter`. Stops as soon as the queue is empty
+/// instead of waiting for more values.
+pub struct MPMCTryIter<'a, T: 'a> {
+    recv: &'a MPMCReceiver<T>,
+}
+
+impl<'a, T: 'a> Iterator for MPMCTryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.recv.try_recv().ok()
+    }
+}
+
+/// Implemented by the receiver handles that can be registered with a
+/// `Select`. Not meant to be implemented outside this crate.
+///
+/// There's no way to peek a stream for a ready value without consuming it
+/// using only what's visible in this module (`try_recv` is the only
+/// nonblocking primitive `InnerRecv` exposes here), so `Select` has each
+/// handle attempt its own `try_recv` directly rather than reporting
+/// readiness and leaving the receive to the caller.
+pub trait Selectable<T> {
+    #[doc(hidden)]
+    fn select_try_recv(&self) -> Result<T, TryRecvError>;
+}
+
+impl<T> Selectable<T> for MPMCReceiver<T> {
+    fn select_try_recv(&self) -> Result<T, TryRecvError> {
+        self.try_recv()
+    }
+}
+
+impl<T> Selectable<T> for MPMCUniReceiver<T> {
+    fn select_try_recv(&self) -> Result<T, TryRecvError> {
+        self.try_recv()
+    }
+}
+
+/// Waits on several `MPMCReceiver`/`MPMCUniReceiver` handles at once,
+/// returning a value from whichever becomes ready first instead of
+/// blocking on a single stream.
+///
+/// This scans the registered handles in order and calls `try_recv` on
+/// each; `recv` repeats that scan with a backoff sleep between passes
+/// (see the `POLL_INTERVAL` note above). A true implementation would
+/// register one `Wait` token shared by every participating stream so a
+/// send on any of them wakes the selector directly, but that requires
+/// hooking into `InnerRecv`'s `Wait` registration, which isn't visible
+/// from this module - so this is a poll, not a park.
+///
+/// Note this deviates from a peek-then-fetch API (`ready`/`try_ready`
+/// returning just an index, leaving the caller to `try_recv` the chosen
+/// handle itself): there's no way to peek a handle without consuming from
+/// it here, so `try_recv`/`recv` consume and hand back `(index, value)`
+/// directly instead.
+///
+/// # Examples
+///
+/// ```
+/// use multiqueue::{mpmc_queue, Select};
+///
+/// let (w1, r1) = mpmc_queue(4);
+/// let (_w2, r2) = mpmc_queue(4);
+///
+/// let mut select = Select::new();
+/// let i1 = select.insert(&r1);
+/// let _i2 = select.insert(&r2);
+///
+/// w1.try_send(1).unwrap();
+///
+/// let (ready, val) = select.try_recv().unwrap();
+/// assert_eq!(ready, i1);
+/// assert_eq!(1, val);
+/// ```
+pub struct Select<'a, T: 'a> {
+    handles: Vec<&'a dyn Selectable<T>>,
+}
+
+/// Whether a `Select` scan found nothing, distinguishing a stream that's
+/// merely empty right now from one where every registered stream has
+/// disconnected and will never produce a value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelectRecvError {
+    Empty,
+    Disconnected,
+}
+
+impl<'a, T: 'a> Select<'a, T> {
+    /// Creates an empty `Select` with no registered streams.
+    pub fn new() -> Select<'a, T> {
+        Select { handles: Vec::new() }
+    }
+
+    /// Registers a receiver handle with this selector, returning the
+    /// index it was assigned.
+    pub fn insert<S: Selectable<T>>(&mut self, handle: &'a S) -> usize {
+        self.handles.push(handle);
+        self.handles.len() - 1
+    }
+
+    /// Scans the registered streams once, without blocking, and returns
+    /// the index and value of the first one with data ready.
+    pub fn try_recv(&self) -> Result<(usize, T), SelectRecvError> {
+        let mut any_connected = false;
+        for (index, handle) in self.handles.iter().enumerate() {
+            match handle.select_try_recv() {
+                Ok(val) => return Ok((index, val)),
+                Err(TryRecvError::Empty) => any_connected = true,
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+        if any_connected {
+            Err(SelectRecvError::Empty)
+        } else {
+            Err(SelectRecvError::Disconnected)
+        }
+    }
+
+    /// Blocks the calling thread, repeatedly scanning the registered
+    /// streams, until one of them has a value. Returns Err(RecvError) once
+    /// every registered stream has disconnected.
+    ///
+    /// WARNING, CPU cost: this spin-polls rather than parking - see
+    /// `MPMCReceiver::recv_timeout`'s doc comment.
+    pub fn recv(&self) -> Result<(usize, T), RecvError> {
+        loop {
+            match self.try_recv() {
+                Ok(result) => return Ok(result),
+                Err(SelectRecvError::Disconnected) => return Err(RecvError),
+                Err(SelectRecvError::Empty) => thread::sleep(POLL_INTERVAL),
+            }
+        }
+    }
+}
+
+
+/// Creates a new MPMC queue with the given `capacity`.
+///
+/// NOTE: `capacity == 0` rendezvous semantics (std's bounded channel lets
+/// callers do this to get a synchronous hand-off) are not implemented by
+/// this module - that requires a handshake slot and waker coordination
+/// between `InnerSend` and `InnerRecv`, which live in the `multiqueue`
+/// module and aren't touched here. Passing 0 today falls straight through
+/// to `MultiQueue::new(0)`, whose behavior is whatever the existing
+/// capacity handling there does with a zero capacity (most likely a
+/// rounding/assert failure, since capacities elsewhere in this crate are
+/// expected to be a positive power of two) - don't rely on it.
+///
+/// STATUS: NOT IMPLEMENTED. chunk0-5 asked for capacity-0 rendezvous mode;
+/// this note documents why it's missing rather than closing the request.
 pub fn mpmc_queue<T>(capacity: Index) -> (MPMCSender<T>, MPMCReceiver<T>) {
     let (send, recv) = MultiQueue::<MPMC<T>, T>::new(capacity);
-    (MPMCSender { sender: send }, MPMCReceiver { reader: recv })
+    (MPMCSender { sender: send },
+     MPMCReceiver {
+         reader: recv,
+         readers: ReaderCount::new(),
+     })
 }
 
+/// Identical to `mpmc_queue`, but lets the caller supply the `Wait`
+/// implementation used to park senders/receivers. See the note on
+/// `mpmc_queue` - `capacity == 0` rendezvous mode is not implemented here.
 pub fn mpmc_queue_with<T, W: Wait + 'static>(capacity: Index,
                                              w: W)
                                              -> (MPMCSender<T>, MPMCReceiver<T>) {
     let (send, recv) = MultiQueue::<MPMC<T>, T>::new_with(capacity, w);
-    (MPMCSender { sender: send }, MPMCReceiver { reader: recv })
+    (MPMCSender { sender: send },
+     MPMCReceiver {
+         reader: recv,
+         readers: ReaderCount::new(),
+     })
 }
 
 unsafe impl<T: Send> Send for MPMCSender<T> {}